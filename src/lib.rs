@@ -51,6 +51,14 @@ pub struct TextureSettings {
     wrap_v: Wrap,
     // Border Color if ClampToBorder is specified as wrap mode
     border_color: [f32; 4],
+    // Enable seamless filtering across cube map face edges.
+    seamless: bool,
+    // Maximum anisotropy for anisotropic filtering (1.0 = off).
+    max_anisotropy: f32,
+    // Bias applied to the computed mip level.
+    lod_bias: f32,
+    // Clamp range for the computed mip level.
+    lod_range: (f32, f32),
 }
 
 impl TextureSettings {
@@ -66,6 +74,10 @@ impl TextureSettings {
             wrap_u: Wrap::ClampToEdge,
             wrap_v: Wrap::ClampToEdge,
             border_color: [0.0, 0.0, 0.0, 1.0],
+            seamless: false,
+            max_anisotropy: 1.0,
+            lod_bias: 0.0,
+            lod_range: (0.0, 1000.0),
         }
     }
 
@@ -194,13 +206,89 @@ impl TextureSettings {
         self
     }
 
+    /// Gets whether seamless cube-map filtering is enabled.
+    pub fn get_seamless(&self) -> bool { self.seamless }
+    /// Sets seamless cube-map filtering.
+    pub fn set_seamless(&mut self, val: bool) { self.seamless = val; }
+    /// Sets seamless cube-map filtering.
+    pub fn seamless(mut self, val: bool) -> Self {
+        self.set_seamless(val);
+        self
+    }
+
+    /// Gets the maximum anisotropy (1.0 = off, 16.0 = typical max).
+    pub fn get_max_anisotropy(&self) -> f32 { self.max_anisotropy }
+    /// Sets the maximum anisotropy.
+    pub fn set_max_anisotropy(&mut self, val: f32) { self.max_anisotropy = val; }
+    /// Sets the maximum anisotropy.
+    pub fn max_anisotropy(mut self, val: f32) -> Self {
+        self.set_max_anisotropy(val);
+        self
+    }
+
+    /// Gets the LOD bias applied to mip selection.
+    pub fn get_lod_bias(&self) -> f32 { self.lod_bias }
+    /// Sets the LOD bias applied to mip selection.
+    pub fn set_lod_bias(&mut self, val: f32) { self.lod_bias = val; }
+    /// Sets the LOD bias applied to mip selection.
+    pub fn lod_bias(mut self, val: f32) -> Self {
+        self.set_lod_bias(val);
+        self
+    }
+
+    /// Gets the `(min, max)` clamp range for mip selection.
+    pub fn get_lod_range(&self) -> (f32, f32) { self.lod_range }
+    /// Sets the `(min, max)` clamp range for mip selection.
+    pub fn set_lod_range(&mut self, val: (f32, f32)) { self.lod_range = val; }
+    /// Sets the `(min, max)` clamp range for mip selection.
+    pub fn lod_range(mut self, val: (f32, f32)) -> Self {
+        self.set_lod_range(val);
+        self
+    }
+
 }
 
 /// Texture format.
 #[derive(Copy, Clone, Debug)]
 pub enum Format {
+    /// A single channel with values 0-255, used for grayscale or alpha masks.
+    R8,
+    /// `(red, green, blue)` with values 0-255.
+    Rgb8,
     /// `(red, green, blue, alpha)` with values 0-255.
     Rgba8,
+    /// `(blue, green, red, alpha)` with values 0-255.
+    Bgra8,
+    /// `(red, green, blue, alpha)` with values 0-255, stored in the sRGB color space.
+    Srgba8,
+    /// `(red, green, blue, alpha)` with 32-bit float values, for HDR or LUT data.
+    Rgba32F,
+}
+
+impl Format {
+    /// Returns the number of channels in the format.
+    pub fn channels(&self) -> u8 {
+        match *self {
+            Format::R8 => 1,
+            Format::Rgb8 => 3,
+            Format::Rgba8 => 4,
+            Format::Bgra8 => 4,
+            Format::Srgba8 => 4,
+            Format::Rgba32F => 4,
+        }
+    }
+
+    /// Returns the number of bytes used to store a single texel.
+    pub fn bytes_per_texel(&self) -> usize {
+        match *self {
+            Format::R8 => 1,
+            Format::Rgb8 => 3,
+            Format::Rgba8 => 4,
+            Format::Bgra8 => 4,
+            Format::Srgba8 => 4,
+            Format::Rgba32F => 16,
+        }
+    }
 }
 
 /// Implemented by texture operations.
@@ -221,6 +309,20 @@ pub trait CreateTexture<F>: TextureOp<F> + ImageSize + Sized {
     ) -> Result<Self, Self::Error>;
 }
 
+/// Implemented by textures for creation from six cube map faces.
+///
+/// The `faces` argument must be ordered +X, -X, +Y, -Y, +Z, -Z.
+pub trait CreateCubeMap<F>: TextureOp<F> + ImageSize + Sized {
+    /// Create a cube map texture from six faces in memory.
+    fn create_cube<S: Into<[u32; 2]>>(
+        factory: &mut F,
+        format: Format,
+        faces: [&[u8]; 6],
+        size: S,
+        settings: &TextureSettings
+    ) -> Result<Self, Self::Error>;
+}
+
 /// Implemented by textures for updating.
 pub trait UpdateTexture<F>: TextureOp<F> + ImageSize + Sized {
     /// Update the texture.
@@ -245,7 +347,9 @@ pub enum Filter {
     /// A Weighted Linear Blend
     Linear,
     /// Nearest Texel
-    Nearest
+    Nearest,
+    /// A high-quality windowed sinc filter with a support radius of 3 texels.
+    Lanczos3,
 }
 
 /// Wrap mode
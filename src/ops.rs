@@ -1,5 +1,8 @@
 //! Image operations for textures.
 
+use Format;
+use Filter;
+
 /// Flips the image vertically.
 pub fn flip_vertical(memory: &[u8], size: [u32; 2], channels: u8) -> Vec<u8> {
     let (width, height, channels) = (size[0] as usize, size[1] as usize,
@@ -20,16 +23,699 @@ pub fn flip_vertical(memory: &[u8], size: [u32; 2], channels: u8) -> Vec<u8> {
 /// Converts from alpha to rgba8.
 pub fn alpha_to_rgba8(memory: &[u8], size: [u32; 2]) -> Vec<u8> {
     let (width, height) = (size[0] as usize, size[1] as usize);
-    let capacity = width * height * 4;
+    let channels = Format::Rgba8.channels() as usize;
+    let capacity = width * height * channels;
     let stride = width;
     let mut res = Vec::with_capacity(capacity);
     for y in 0..height {
         for x in 0..width {
-            res.push(255);
-            res.push(255);
-            res.push(255);
+            res.resize(res.len() + channels - 1, 255);
             res.push(memory[x + y * stride]);
         }
     }
     res
 }
+
+/// Generates a full mipmap chain from a base image, down to a 1x1 level.
+///
+/// Each level is produced from the previous one with a 2x2 box filter,
+/// averaging the four parent texels per channel. Each level's extent is the
+/// parent's halved and rounded down, never below 1. Parent indices are
+/// clamped to the last valid row or column, which only comes into play when
+/// a parent dimension is 1; when a parent dimension is odd and larger than 1,
+/// its last row or column is not sampled. Returns every level in order,
+/// starting with the base image at index 0.
+pub fn generate_mipmaps(memory: &[u8], size: [u32; 2], channels: u8) -> Vec<(Vec<u8>, [u32; 2])> {
+    let channels = channels as usize;
+    let (w, h) = (size[0], size[1]);
+    let levels = (32 - (w.max(h)).leading_zeros()) as usize;
+
+    let mut chain = Vec::with_capacity(levels);
+    chain.push((memory.to_vec(), [w, h]));
+
+    for level in 1..levels {
+        let (parent, parent_size) = chain[level - 1].clone();
+        let (pw, ph) = (parent_size[0] as usize, parent_size[1] as usize);
+        let (ow, oh) = ((pw / 2).max(1), (ph / 2).max(1));
+
+        let mut out = vec![0u8; ow * oh * channels];
+        for y in 0..oh {
+            let y0 = (2 * y).min(ph - 1);
+            let y1 = (2 * y + 1).min(ph - 1);
+            for x in 0..ow {
+                let x0 = (2 * x).min(pw - 1);
+                let x1 = (2 * x + 1).min(pw - 1);
+                for c in 0..channels {
+                    let sum = parent[(x0 + y0 * pw) * channels + c] as u32
+                        + parent[(x1 + y0 * pw) * channels + c] as u32
+                        + parent[(x0 + y1 * pw) * channels + c] as u32
+                        + parent[(x1 + y1 * pw) * channels + c] as u32;
+                    out[(x + y * ow) * channels + c] = ((sum + 2) / 4) as u8;
+                }
+            }
+        }
+
+        chain.push((out, [ow as u32, oh as u32]));
+    }
+
+    chain
+}
+
+/// Settings for procedural Perlin turbulence noise generation.
+#[derive(Clone, Copy)]
+pub struct TurbulenceSettings {
+    // Number of octaves summed together (ignored unless `fractal` is set).
+    octaves: u32,
+    // Base spatial frequency of the first octave, in cycles per texel.
+    frequency: f64,
+    // Amplitude multiplier applied to each successive octave.
+    persistence: f64,
+    // Seed for the permutation table.
+    seed: u32,
+    // Whether to sum multiple octaves (fractal) or sample a single one.
+    fractal: bool,
+    // Whether the gradient lookup wraps so the result tiles seamlessly.
+    tileable: bool,
+}
+
+impl TurbulenceSettings {
+    /// Create default settings.
+    pub fn new() -> TurbulenceSettings {
+        TurbulenceSettings {
+            octaves: 4,
+            frequency: 1.0 / 32.0,
+            persistence: 0.5,
+            seed: 0,
+            fractal: true,
+            tileable: false,
+        }
+    }
+
+    /// Gets the octave count.
+    pub fn get_octaves(&self) -> u32 { self.octaves }
+    /// Sets the octave count.
+    pub fn set_octaves(&mut self, val: u32) { self.octaves = val; }
+    /// Sets the octave count.
+    pub fn octaves(mut self, val: u32) -> Self {
+        self.set_octaves(val);
+        self
+    }
+
+    /// Gets the base frequency.
+    pub fn get_frequency(&self) -> f64 { self.frequency }
+    /// Sets the base frequency.
+    pub fn set_frequency(&mut self, val: f64) { self.frequency = val; }
+    /// Sets the base frequency.
+    pub fn frequency(mut self, val: f64) -> Self {
+        self.set_frequency(val);
+        self
+    }
+
+    /// Gets the persistence (amplitude falloff per octave).
+    pub fn get_persistence(&self) -> f64 { self.persistence }
+    /// Sets the persistence.
+    pub fn set_persistence(&mut self, val: f64) { self.persistence = val; }
+    /// Sets the persistence.
+    pub fn persistence(mut self, val: f64) -> Self {
+        self.set_persistence(val);
+        self
+    }
+
+    /// Gets the seed.
+    pub fn get_seed(&self) -> u32 { self.seed }
+    /// Sets the seed.
+    pub fn set_seed(&mut self, val: u32) { self.seed = val; }
+    /// Sets the seed.
+    pub fn seed(mut self, val: u32) -> Self {
+        self.set_seed(val);
+        self
+    }
+
+    /// Gets whether multiple octaves are summed.
+    pub fn get_fractal(&self) -> bool { self.fractal }
+    /// Sets whether multiple octaves are summed.
+    pub fn set_fractal(&mut self, val: bool) { self.fractal = val; }
+    /// Sets whether multiple octaves are summed.
+    pub fn fractal(mut self, val: bool) -> Self {
+        self.set_fractal(val);
+        self
+    }
+
+    /// Gets whether the noise tiles seamlessly.
+    pub fn get_tileable(&self) -> bool { self.tileable }
+    /// Sets whether the noise tiles seamlessly.
+    pub fn set_tileable(&mut self, val: bool) { self.tileable = val; }
+    /// Sets whether the noise tiles seamlessly.
+    pub fn tileable(mut self, val: bool) -> Self {
+        self.set_tileable(val);
+        self
+    }
+}
+
+impl Default for TurbulenceSettings {
+    fn default() -> TurbulenceSettings {
+        TurbulenceSettings::new()
+    }
+}
+
+// A simple seeded PRNG (linear congruential generator) used only to shuffle
+// the permutation table; it does not need to be cryptographically strong.
+fn lcg_next(state: &mut u32) -> u32 {
+    *state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+    *state
+}
+
+// Builds a permutation table of 0..256 shuffled by `seed`, duplicated to 512
+// entries so lookups never need to wrap the index manually.
+fn build_permutation(seed: u32) -> [usize; 512] {
+    let mut perm = [0usize; 256];
+    for (i, slot) in perm.iter_mut().enumerate() {
+        *slot = i;
+    }
+    let mut state = seed ^ 0x9e3779b9;
+    for i in (1..256).rev() {
+        let j = (lcg_next(&mut state) as usize) % (i + 1);
+        perm.swap(i, j);
+    }
+    let mut table = [0usize; 512];
+    for i in 0..512 {
+        table[i] = perm[i & 255];
+    }
+    table
+}
+
+const GRADIENTS: [[f64; 2]; 8] = [
+    [1.0, 1.0], [-1.0, 1.0], [1.0, -1.0], [-1.0, -1.0],
+    [1.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [0.0, -1.0],
+];
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + t * (b - a)
+}
+
+fn dot_grad(perm: &[usize; 512], hash: usize, x: f64, y: f64) -> f64 {
+    let g = GRADIENTS[perm[hash] & 7];
+    g[0] * x + g[1] * y
+}
+
+// Classic 2D Perlin gradient noise, returning a value in roughly `-1..1`.
+// When `period` is set, lattice coordinates first wrap modulo the
+// per-axis period so successive tiles of the noise connect seamlessly,
+// then get masked into the `0..255` range the permutation table indexes
+// (it is only ever duplicated to 512 entries, so a period larger than
+// 256 must still be folded down before it is used as a hash index).
+fn perlin2(perm: &[usize; 512], x: f64, y: f64, period: Option<(u32, u32)>) -> f64 {
+    let wrap_cell = |i: i64, p: u32| if p > 0 { i.rem_euclid(p as i64) } else { i };
+    let hash_index = |i: i64| (i & 255) as usize;
+
+    let x0 = x.floor() as i64;
+    let y0 = y.floor() as i64;
+    let (xi0, yi0) = match period {
+        Some((px, py)) => (hash_index(wrap_cell(x0, px)), hash_index(wrap_cell(y0, py))),
+        None => (hash_index(x0), hash_index(y0)),
+    };
+    let (xi1, yi1) = match period {
+        Some((px, py)) => (hash_index(wrap_cell(x0 + 1, px)), hash_index(wrap_cell(y0 + 1, py))),
+        None => (hash_index(x0 + 1), hash_index(y0 + 1)),
+    };
+
+    let xf = x - x0 as f64;
+    let yf = y - y0 as f64;
+    let u = fade(xf);
+    let v = fade(yf);
+
+    let aa = perm[perm[xi0] + yi0];
+    let ab = perm[perm[xi0] + yi1];
+    let ba = perm[perm[xi1] + yi0];
+    let bb = perm[perm[xi1] + yi1];
+
+    let x1 = lerp(dot_grad(perm, aa, xf, yf), dot_grad(perm, ba, xf - 1.0, yf), u);
+    let x2 = lerp(dot_grad(perm, ab, xf, yf - 1.0), dot_grad(perm, bb, xf - 1.0, yf - 1.0), u);
+    lerp(x1, x2, v)
+}
+
+/// Generates a procedural noise texture using Perlin turbulence.
+///
+/// `format` must be `R8` (single channel) or `Rgba8` (noise broadcast to
+/// the color channels with an opaque alpha). When `settings.get_fractal()`
+/// is set, `settings.get_octaves()` noise layers at doubling frequencies are
+/// summed together with `settings.get_persistence()` as the amplitude falloff;
+/// otherwise a single octave is sampled. The raw turbulence sum rarely
+/// reaches its theoretical maximum (2D gradient noise peaks well below
+/// 1.0), so the result is rescaled by the actual min/max observed across
+/// the image rather than the theoretical bound, so the output spans the
+/// full `0..=255` range.
+pub fn turbulence(size: [u32; 2], format: Format, settings: &TurbulenceSettings) -> Vec<u8> {
+    let (width, height) = (size[0] as usize, size[1] as usize);
+    let channels = format.channels() as usize;
+    let perm = build_permutation(settings.get_seed());
+    let octaves = if settings.get_fractal() { settings.get_octaves().max(1) } else { 1 };
+    let tileable = settings.get_tileable();
+
+    let mut raw = vec![0.0f64; width * height];
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = 0.0;
+            let mut amplitude = 1.0;
+            for i in 0..octaves {
+                let f = settings.get_frequency() * (1u32 << i) as f64;
+                // For tiling, snap each axis to an integer number of lattice
+                // cells and re-derive that axis's frequency from it, so
+                // `coord * freq` advances exactly `period` cells across the
+                // image and the two edges of the tile line up.
+                let (sx, sy, octave_period) = if tileable {
+                    let period_x = (width as f64 * f).round().max(1.0) as u32;
+                    let period_y = (height as f64 * f).round().max(1.0) as u32;
+                    let freq_x = period_x as f64 / width as f64;
+                    let freq_y = period_y as f64 / height as f64;
+                    (x as f64 * freq_x, y as f64 * freq_y, Some((period_x, period_y)))
+                } else {
+                    (x as f64 * f, y as f64 * f, None)
+                };
+                sum += amplitude * perlin2(&perm, sx, sy, octave_period).abs();
+                amplitude *= settings.get_persistence();
+            }
+            raw[x + y * width] = sum;
+            min = min.min(sum);
+            max = max.max(sum);
+        }
+    }
+    let range = max - min;
+
+    let mut res = vec![0u8; width * height * channels];
+    for y in 0..height {
+        for x in 0..width {
+            let normalized = if range > 0.0 { (raw[x + y * width] - min) / range } else { 0.0 };
+            let value = (normalized.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+            let base = (x + y * width) * channels;
+            if channels == 1 {
+                res[base] = value;
+            } else {
+                for c in 0..(channels - 1) {
+                    res[base + c] = value;
+                }
+                res[base + channels - 1] = 255;
+            }
+        }
+    }
+    res
+}
+
+/// A compositing mode for [`blend`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BlendMode {
+    /// Standard src-over alpha compositing.
+    Normal,
+    /// Multiplies source and destination color channels.
+    Multiply,
+    /// Inverse-multiplies source and destination color channels.
+    Screen,
+    /// Adds source and destination color channels.
+    Add,
+    /// Subtracts the source from the destination color channels.
+    Subtract,
+}
+
+// Applies `mode` to a single pair of normalized `0..1` color channels,
+// not including alpha.
+fn blend_channel(mode: BlendMode, dst: f64, src: f64) -> f64 {
+    match mode {
+        BlendMode::Normal => src,
+        BlendMode::Multiply => dst * src,
+        BlendMode::Screen => 1.0 - (1.0 - dst) * (1.0 - src),
+        BlendMode::Add => dst + src,
+        BlendMode::Subtract => dst - src,
+    }
+}
+
+// Splits a channel count into whether it carries a trailing alpha channel
+// (2 = gray+alpha, 4 = rgba) and how many leading color channels precede it.
+fn split_alpha_channel(channels: usize) -> (bool, usize) {
+    let has_alpha = channels == 2 || channels == 4;
+    let color_channels = if has_alpha { channels - 1 } else { channels };
+    (has_alpha, color_channels)
+}
+
+/// Composites `src` over `dst` using `mode`, returning a new buffer the
+/// same size as `dst`.
+///
+/// Color channels are blended in floating point normalized to `0..1`. When
+/// `channels` includes an alpha channel (2 or 4 channels), the blended color is
+/// then composited over the destination using straight alpha: `out =
+/// blended * a + dst * (1 - a)`. Without an alpha channel, the blended
+/// color is used directly. The result is quantized back to `0..=255` with
+/// rounding.
+pub fn blend(dst: &[u8], src: &[u8], size: [u32; 2], channels: u8, mode: BlendMode) -> Vec<u8> {
+    let (width, height) = (size[0] as usize, size[1] as usize);
+    let channels = channels as usize;
+    let (has_alpha, color_channels) = split_alpha_channel(channels);
+
+    let mut res = vec![0u8; width * height * channels];
+    for y in 0..height {
+        for x in 0..width {
+            let base = (x + y * width) * channels;
+            let a = if has_alpha {
+                src[base + color_channels] as f64 / 255.0
+            } else {
+                1.0
+            };
+            for c in 0..color_channels {
+                let d = dst[base + c] as f64 / 255.0;
+                let s = src[base + c] as f64 / 255.0;
+                let blended = blend_channel(mode, d, s);
+                let out = blended * a + d * (1.0 - a);
+                res[base + c] = (out.clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+            if has_alpha {
+                let d_a = dst[base + color_channels] as f64 / 255.0;
+                let out_a = a + d_a * (1.0 - a);
+                res[base + color_channels] = (out_a.clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        }
+    }
+    res
+}
+
+/// Fills a buffer of the given size and channel count with a single color.
+///
+/// `color` must have exactly `channels` entries; it is repeated for every
+/// texel.
+///
+/// Panics if `color.len()` does not equal `channels`.
+pub fn fill(size: [u32; 2], channels: u8, color: &[u8]) -> Vec<u8> {
+    let (width, height) = (size[0] as usize, size[1] as usize);
+    let channels = channels as usize;
+    assert_eq!(color.len(), channels,
+        "fill: color.len() ({}) must equal channels ({})", color.len(), channels);
+    let mut res = vec![0u8; width * height * channels];
+    for texel in res.chunks_mut(channels) {
+        texel.copy_from_slice(color);
+    }
+    res
+}
+
+/// Extracts a single channel from an interleaved buffer into its own
+/// single-channel buffer.
+pub fn extract_channel(memory: &[u8], size: [u32; 2], channels: u8, channel: u8) -> Vec<u8> {
+    let (width, height) = (size[0] as usize, size[1] as usize);
+    let channels = channels as usize;
+    let channel = channel as usize;
+    let mut res = vec![0u8; width * height];
+    for i in 0..width * height {
+        res[i] = memory[i * channels + channel];
+    }
+    res
+}
+
+/// Writes a single-channel buffer into one channel of an interleaved
+/// buffer, in place.
+pub fn set_channel(memory: &mut [u8], size: [u32; 2], channels: u8, channel: u8, values: &[u8]) {
+    let (width, height) = (size[0] as usize, size[1] as usize);
+    let channels = channels as usize;
+    let channel = channel as usize;
+    for i in 0..width * height {
+        memory[i * channels + channel] = values[i];
+    }
+}
+
+fn srgb_to_linear_channel(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb_channel(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Applies `transfer` to every color channel of an `Rgb8`/`Rgba8` buffer,
+// leaving any alpha channel untouched.
+fn apply_transfer(memory: &[u8], size: [u32; 2], channels: u8, transfer: fn(f64) -> f64) -> Vec<u8> {
+    let (width, height) = (size[0] as usize, size[1] as usize);
+    let channels = channels as usize;
+    let (has_alpha, color_channels) = split_alpha_channel(channels);
+
+    let mut res = vec![0u8; width * height * channels];
+    for texel in 0..width * height {
+        let base = texel * channels;
+        for c in 0..color_channels {
+            let normalized = memory[base + c] as f64 / 255.0;
+            res[base + c] = (transfer(normalized).clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+        if has_alpha {
+            res[base + color_channels] = memory[base + color_channels];
+        }
+    }
+    res
+}
+
+/// Converts an `Rgb8`/`Rgba8` buffer from sRGB to linear color space.
+///
+/// The alpha channel, if present, is left untouched.
+pub fn srgb_to_linear(memory: &[u8], size: [u32; 2], channels: u8) -> Vec<u8> {
+    apply_transfer(memory, size, channels, srgb_to_linear_channel)
+}
+
+/// Converts an `Rgb8`/`Rgba8` buffer from linear to sRGB color space.
+///
+/// The alpha channel, if present, is left untouched.
+pub fn linear_to_srgb(memory: &[u8], size: [u32; 2], channels: u8) -> Vec<u8> {
+    apply_transfer(memory, size, channels, linear_to_srgb_channel)
+}
+
+/// The support radius of a resampling filter, in source texels.
+fn filter_radius(filter: Filter) -> f64 {
+    match filter {
+        Filter::Nearest => 0.5,
+        Filter::Linear => 1.0,
+        Filter::Lanczos3 => 3.0,
+    }
+}
+
+/// `sinc(x) = sin(pi * x) / (pi * x)`, with `sinc(0) = 1`.
+fn sinc(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else {
+        let px = ::std::f64::consts::PI * x;
+        px.sin() / px
+    }
+}
+
+/// Evaluates the resampling kernel at a distance `x` from the sample center.
+fn filter_weight(filter: Filter, x: f64) -> f64 {
+    match filter {
+        Filter::Nearest => if x.abs() < 0.5 { 1.0 } else { 0.0 },
+        Filter::Linear => {
+            let x = x.abs();
+            if x < 1.0 { 1.0 - x } else { 0.0 }
+        }
+        Filter::Lanczos3 => {
+            let x = x.abs();
+            if x < 3.0 { sinc(x) * sinc(x / 3.0) } else { 0.0 }
+        }
+    }
+}
+
+/// A single source texel and its normalized weight in a resampled output coordinate.
+struct Contribution {
+    index: usize,
+    weight: f64,
+}
+
+/// Computes, for every output coordinate along an axis, the weighted source
+/// texels that contribute to it.
+fn resize_contributions(src_len: usize, dst_len: usize, filter: Filter) -> Vec<Vec<Contribution>> {
+    let radius = filter_radius(filter);
+    // When downscaling, widen the kernel so it also acts as a low-pass filter.
+    let filter_scale = if dst_len < src_len {
+        src_len as f64 / dst_len as f64
+    } else {
+        1.0
+    };
+    let radius = radius * filter_scale;
+
+    (0..dst_len).map(|o| {
+        let center = (o as f64 + 0.5) * src_len as f64 / dst_len as f64 - 0.5;
+        let lo = (center - radius).floor() as isize;
+        let hi = (center + radius).ceil() as isize;
+
+        let mut contributions: Vec<Contribution> = Vec::new();
+        let mut sum = 0.0;
+        for i in lo..=hi {
+            let w = filter_weight(filter, (i as f64 - center) / filter_scale);
+            let clamped = i.max(0).min(src_len as isize - 1) as usize;
+            sum += w;
+            // Edge coordinates clamp to the valid range, so out-of-range
+            // samples fold their weight into the nearest valid texel.
+            if let Some(c) = contributions.iter_mut().find(|c| c.index == clamped) {
+                c.weight += w;
+            } else {
+                contributions.push(Contribution { index: clamped, weight: w });
+            }
+        }
+        if sum != 0.0 {
+            for c in &mut contributions {
+                c.weight /= sum;
+            }
+        }
+        contributions
+    }).collect()
+}
+
+/// Resamples an image from `src_size` to `dst_size` using the given filter.
+///
+/// Implemented as a separable two-pass resampler: the image is resized
+/// horizontally, then the intermediate result is resized vertically.
+pub fn resize(
+    memory: &[u8],
+    src_size: [u32; 2],
+    dst_size: [u32; 2],
+    channels: u8,
+    filter: Filter,
+) -> Vec<u8> {
+    let (src_w, src_h) = (src_size[0] as usize, src_size[1] as usize);
+    let (dst_w, dst_h) = (dst_size[0] as usize, dst_size[1] as usize);
+    let channels = channels as usize;
+
+    let horizontal = resize_contributions(src_w, dst_w, filter);
+    let vertical = resize_contributions(src_h, dst_h, filter);
+
+    // Horizontal pass: src_w x src_h -> dst_w x src_h.
+    let mut mid = vec![0u8; dst_w * src_h * channels];
+    for y in 0..src_h {
+        for x in 0..dst_w {
+            for c in 0..channels {
+                let mut acc = 0.0;
+                for contribution in &horizontal[x] {
+                    let idx = (contribution.index + y * src_w) * channels + c;
+                    acc += memory[idx] as f64 * contribution.weight;
+                }
+                mid[(x + y * dst_w) * channels + c] = acc.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+
+    // Vertical pass: dst_w x src_h -> dst_w x dst_h.
+    let mut res = vec![0u8; dst_w * dst_h * channels];
+    for y in 0..dst_h {
+        for x in 0..dst_w {
+            for c in 0..channels {
+                let mut acc = 0.0;
+                for contribution in &vertical[y] {
+                    let idx = (x + contribution.index * dst_w) * channels + c;
+                    acc += mid[idx] as f64 * contribution.weight;
+                }
+                res[(x + y * dst_w) * channels + c] = acc.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resize_identity_is_unchanged() {
+        let memory: Vec<u8> = (0..(4 * 4 * 3)).map(|i| (i * 7) as u8).collect();
+        let resized = resize(&memory, [4, 4], [4, 4], 3, Filter::Linear);
+        assert_eq!(resized, memory);
+    }
+
+    #[test]
+    fn generate_mipmaps_averages_2x2_blocks() {
+        // A 4x4 single-channel image split into four constant 2x2 quadrants.
+        let memory: Vec<u8> = vec![
+            10, 10, 20, 20,
+            10, 10, 20, 20,
+            30, 30, 40, 40,
+            30, 30, 40, 40,
+        ];
+        let chain = generate_mipmaps(&memory, [4, 4], 1);
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain[0], (memory, [4, 4]));
+        assert_eq!(chain[1], (vec![10, 20, 30, 40], [2, 2]));
+        assert_eq!(chain[2], (vec![25], [1, 1]));
+    }
+
+    #[test]
+    fn blend_normal_is_straight_alpha_over() {
+        // A single opaque black destination texel blended with a half-alpha
+        // white source texel should land halfway between the two colors.
+        let dst = [0, 0, 0, 255];
+        let src = [255, 255, 255, 128];
+        let out = blend(&dst, &src, [1, 1], 4, BlendMode::Normal);
+        assert_eq!(out, [128, 128, 128, 255]);
+    }
+
+    #[test]
+    fn fill_repeats_color_for_every_texel() {
+        let out = fill([2, 2], 3, &[1, 2, 3]);
+        assert_eq!(out, [1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_panics_on_color_length_mismatch() {
+        fill([1, 1], 4, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn extract_and_set_channel_round_trip() {
+        let memory = [1, 2, 3, 4, 5, 6, 7, 8];
+        let green = extract_channel(&memory, [2, 2], 2, 1);
+        assert_eq!(green, [2, 4, 6, 8]);
+
+        let mut rebuilt = [0u8; 8];
+        set_channel(&mut rebuilt, [2, 2], 2, 1, &green);
+        assert_eq!(rebuilt, [0, 2, 0, 4, 0, 6, 0, 8]);
+    }
+
+    #[test]
+    fn srgb_linear_round_trip_preserves_color_and_alpha() {
+        let memory = [12, 64, 200, 77];
+        let linear = srgb_to_linear(&memory, [1, 1], 4);
+        let back = linear_to_srgb(&linear, [1, 1], 4);
+        for (original, roundtripped) in memory.iter().zip(back.iter()) {
+            assert!((*original as i32 - *roundtripped as i32).abs() <= 1);
+        }
+        // The alpha channel must be passed through untouched, not gamma-corrected.
+        assert_eq!(linear[3], 77);
+        assert_eq!(back[3], 77);
+    }
+
+    #[test]
+    fn turbulence_tileable_does_not_panic() {
+        // Square, non-square, small and large sizes, at frequencies both
+        // below and above the permutation table's natural 256-cell period.
+        let cases: [([u32; 2], f64); 6] = [
+            ([64, 64], 1.0 / 32.0),
+            ([300, 150], 1.0 / 32.0),
+            ([256, 256], 1.0),
+            ([17, 31], 1.0 / 8.0),
+            ([512, 64], 1.0),
+            ([1, 1], 1.0 / 32.0),
+        ];
+        for (size, frequency) in cases.iter() {
+            let settings = TurbulenceSettings::new().tileable(true).frequency(*frequency);
+            let out = turbulence(*size, Format::Rgba8, &settings);
+            assert_eq!(out.len(), size[0] as usize * size[1] as usize * 4);
+        }
+    }
+}